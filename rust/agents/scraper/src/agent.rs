@@ -1,5 +1,7 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use eyre::{eyre, WrapErr};
@@ -18,6 +20,18 @@ use hyperlane_core::HyperlaneDomain;
 
 use crate::chain_scraper::HyperlaneSqlDb;
 use crate::db::ScraperDb;
+use crate::db_config::{DbConnection, RawDbConnection};
+use crate::backfill::BackfillRange;
+use crate::cache::{EntityCacheCapacities, RawEntityCacheCapacities};
+use crate::job::{IndexerJob, JobType};
+use crate::notifier::{NotificationEvent, Notifier, RemoteNotifier, RemoteNotifierConfig};
+
+/// The bound on the notifier channel. Sync tasks drop a notification rather
+/// than block indexing if the notifier task falls behind.
+const NOTIFIER_CHANNEL_SIZE: usize = 1024;
+/// Default number of blocks a chain may fall behind the provider's
+/// finalized tip before a "chain lagging" notification fires.
+const DEFAULT_LAG_THRESHOLD_BLOCKS: u64 = 50;
 
 /// A message explorer scraper agent
 #[derive(Debug)]
@@ -27,6 +41,11 @@ pub struct Scraper {
     contract_sync_metrics: Arc<ContractSyncMetrics>,
     metrics: Arc<CoreMetrics>,
     scrapers: HashMap<u32, ChainScraper>,
+    notifier: Option<Arc<dyn Notifier>>,
+    notifier_tx: Option<tokio::sync::mpsc::Sender<NotificationEvent>>,
+    notifier_rx: tokio::sync::Mutex<Option<tokio::sync::mpsc::Receiver<NotificationEvent>>>,
+    lag_threshold_blocks: u64,
+    fail_fast: bool,
 }
 
 #[derive(Debug)]
@@ -34,21 +53,74 @@ struct ChainScraper {
     index_settings: IndexSettings,
     db: HyperlaneSqlDb,
     domain: HyperlaneDomain,
+    /// The set of indexing jobs enabled for this chain.
+    jobs: Vec<JobType>,
+    /// When set, this chain runs a one-shot bounded backfill over this
+    /// range instead of the long-running continuous cursors.
+    backfill: Option<BackfillRange>,
 }
 
 decl_settings!(Scraper,
     Parsed {
-        db: String,
+        db: DbConnection,
         chains_to_scrape: Vec<HyperlaneDomain>,
+        /// The indexing jobs enabled per chain, keyed by domain id. Chains
+        /// without an explicit `indexers` override run every `JobType`.
+        indexers: HashMap<u32, Vec<JobType>>,
+        /// Outbound notifier endpoint, if configured.
+        notifier: Option<RemoteNotifierConfig>,
+        /// Number of blocks a chain may fall behind the provider's finalized
+        /// tip before a "chain lagging" notification fires.
+        lag_threshold_blocks: u64,
+        /// Per-chain bounded backfill ranges, keyed by domain id. A chain
+        /// with an entry here runs a one-shot backfill instead of the
+        /// continuous cursors.
+        backfill: HashMap<u32, BackfillRange>,
+        /// When backfilling, abort the whole agent on the first chunk that
+        /// errors instead of logging and continuing.
+        fail_fast: bool,
+        /// Capacities of `HyperlaneSqlDb`'s write-through entity caches
+        /// (tx hash, block number, address -> id lookups).
+        cache_capacities: EntityCacheCapacities,
     },
     Raw {
-        /// Database connection string
-        db: Option<String>,
+        /// Structured database connection config (url, optional TLS, pool
+        /// size, statement timeout).
+        db: Option<RawDbConnection>,
         /// Comma separated list of chains to scrape
         chainstoscrape: Option<String>,
+        /// Per-chain comma separated list of indexer job types to run
+        /// (`messagedispatch`, `messagedelivery`, `gaspayment`). Chains
+        /// omitted here run every job type.
+        indexers: Option<HashMap<String, String>>,
+        /// Outbound notifier endpoint for newly indexed events and chain
+        /// lag alerts.
+        notifier: Option<RemoteNotifierConfig>,
+        /// Number of blocks a chain may fall behind the provider's finalized
+        /// tip before a "chain lagging" notification fires. Defaults to 50.
+        lag_threshold_blocks: Option<u64>,
+        /// Per-chain bounded backfill ranges, keyed by chain name.
+        backfill: Option<HashMap<String, BackfillRange>>,
+        /// When backfilling, abort the whole agent on the first chunk that
+        /// errors instead of logging and continuing. Defaults to `false`.
+        fail_fast: Option<bool>,
+        /// Capacities of `HyperlaneSqlDb`'s write-through entity caches.
+        /// Each defaults to `DEFAULT_CACHE_CAPACITY` when omitted.
+        cache_capacities: Option<RawEntityCacheCapacities>,
     }
 );
 
+/// Validates and converts a [`RawDbConnection`] into a [`DbConnection`] (see
+/// `DbConnection`'s `TryFrom` impl), surfacing failures through the same
+/// `ConfigParsingError` accumulator as the rest of this config.
+fn parse_db_connection(
+    raw: RawDbConnection,
+    cwp: &ConfigPath,
+    err: &mut ConfigParsingError,
+) -> Option<DbConnection> {
+    DbConnection::try_from(raw).take_err(err, || cwp.clone())
+}
+
 impl FromRawConf<'_, RawScraperSettings> for ScraperSettings {
     fn from_config_filtered(
         raw: RawScraperSettings,
@@ -59,8 +131,9 @@ impl FromRawConf<'_, RawScraperSettings> for ScraperSettings {
 
         let db = raw
             .db
-            .ok_or_else(|| eyre!("Missing `db` connection string"))
-            .take_err(&mut err, || cwp + "db");
+            .ok_or_else(|| eyre!("Missing `db` connection config"))
+            .take_err(&mut err, || cwp + "db")
+            .and_then(|raw_db| parse_db_connection(raw_db, &(cwp + "db"), &mut err));
 
         let Some(chains_to_scrape) = raw
             .chainstoscrape
@@ -91,11 +164,44 @@ impl FromRawConf<'_, RawScraperSettings> for ScraperSettings {
             })
             .unwrap_or_default();
 
+        let raw_indexers = raw.indexers.unwrap_or_default();
+        let mut indexers = HashMap::with_capacity(chains_to_scrape.len());
+        for domain in chains_to_scrape.iter() {
+            let jobs = match raw_indexers.get(domain.name()) {
+                Some(list) => list
+                    .split(',')
+                    .map(|s| s.parse::<JobType>())
+                    .collect::<eyre::Result<Vec<_>>>()
+                    .take_err(&mut err, || cwp + "indexers" + domain.name())
+                    .unwrap_or_else(JobType::all),
+                None => JobType::all(),
+            };
+            indexers.insert(domain.id(), jobs);
+        }
+
+        let raw_backfill = raw.backfill.unwrap_or_default();
+        let backfill: HashMap<u32, BackfillRange> = chains_to_scrape
+            .iter()
+            .filter_map(|domain| {
+                raw_backfill
+                    .get(domain.name())
+                    .map(|range| (domain.id(), *range))
+            })
+            .collect();
+
         err.into_result()?;
         Ok(Self {
             base: base.unwrap(),
             db: db.unwrap(),
             chains_to_scrape,
+            indexers,
+            notifier: raw.notifier,
+            lag_threshold_blocks: raw
+                .lag_threshold_blocks
+                .unwrap_or(DEFAULT_LAG_THRESHOLD_BLOCKS),
+            backfill,
+            fail_fast: raw.fail_fast.unwrap_or(false),
+            cache_capacities: raw.cache_capacities.map(Into::into).unwrap_or_default(),
         })
     }
 }
@@ -118,6 +224,17 @@ impl BaseAgent for Scraper {
         let contract_sync_metrics = Arc::new(ContractSyncMetrics::new(&metrics));
         let mut scrapers: HashMap<u32, ChainScraper> = HashMap::new();
 
+        let notifier: Option<Arc<dyn Notifier>> = settings
+            .notifier
+            .clone()
+            .map(|config| Arc::new(RemoteNotifier::new(config)) as Arc<dyn Notifier>);
+        let (notifier_tx, notifier_rx) = if notifier.is_some() {
+            let (tx, rx) = tokio::sync::mpsc::channel(NOTIFIER_CHANNEL_SIZE);
+            (Some(tx), Some(rx))
+        } else {
+            (None, None)
+        };
+
         for domain in settings.chains_to_scrape.iter() {
             let chain_setup = settings.chain_setup(domain).expect("Missing chain config");
             let db = HyperlaneSqlDb::new(
@@ -129,14 +246,24 @@ impl BaseAgent for Scraper {
                     .await?
                     .into(),
                 &chain_setup.index.clone(),
+                notifier_tx.clone(),
+                settings.cache_capacities,
             )
             .await?;
+            let jobs = settings
+                .indexers
+                .get(&domain.id())
+                .cloned()
+                .unwrap_or_else(JobType::all);
+            let backfill = settings.backfill.get(&domain.id()).copied();
             scrapers.insert(
                 domain.id(),
                 ChainScraper {
                     domain: domain.clone(),
                     db,
                     index_settings: chain_setup.index.clone(),
+                    jobs,
+                    backfill,
                 },
             );
         }
@@ -148,14 +275,28 @@ impl BaseAgent for Scraper {
             metrics,
             contract_sync_metrics,
             scrapers,
+            notifier,
+            notifier_tx,
+            notifier_rx: tokio::sync::Mutex::new(notifier_rx),
+            lag_threshold_blocks: settings.lag_threshold_blocks,
+            fail_fast: settings.fail_fast,
         })
     }
 
     #[allow(clippy::async_yields_async)]
     async fn run(&self) -> Instrumented<JoinHandle<eyre::Result<()>>> {
-        let mut tasks = Vec::with_capacity(self.scrapers.len());
-        for domain in self.scrapers.keys() {
-            tasks.push(self.scrape(*domain).await);
+        let mut tasks = Vec::with_capacity(self.scrapers.len() * 2 + 1);
+        for domain_id in self.scrapers.keys().copied() {
+            let scraper = self.scrapers.get(&domain_id).unwrap();
+            if scraper.backfill.is_some() {
+                tasks.push(self.backfill(domain_id).await);
+            } else {
+                tasks.push(self.scrape(domain_id).await);
+                tasks.push(self.watch_lag(domain_id).await);
+            }
+        }
+        if let Some(task) = self.notify().await {
+            tasks.push(task);
         }
         run_all(tasks)
     }
@@ -163,46 +304,186 @@ impl BaseAgent for Scraper {
 
 impl Scraper {
     /// Sync contract data and other blockchain with the current chain state.
-    /// This will spawn long-running contract sync tasks
+    /// This will spawn one long-running contract sync task per `JobType`
+    /// configured for this chain (see `ScraperSettings::indexers`).
     async fn scrape(&self, domain_id: u32) -> Instrumented<JoinHandle<eyre::Result<()>>> {
         let scraper = self.scrapers.get(&domain_id).unwrap();
         let db = scraper.db.clone();
         let index_settings = scraper.index_settings.clone();
         let domain = scraper.domain.clone();
 
-        let mut tasks = Vec::with_capacity(2);
-        tasks.push(
-            self.build_message_indexer(
-                domain.clone(),
-                self.metrics.clone(),
-                self.contract_sync_metrics.clone(),
-                db.clone(),
-                index_settings.clone(),
-            )
-            .await,
-        );
-        tasks.push(
-            self.build_delivery_indexer(
-                domain.clone(),
-                self.metrics.clone(),
-                self.contract_sync_metrics.clone(),
-                db.clone(),
-                index_settings.clone(),
-            )
-            .await,
-        );
-        tasks.push(
-            self.build_interchain_gas_payment_indexer(
-                domain,
-                self.metrics.clone(),
-                self.contract_sync_metrics.clone(),
-                db,
-                index_settings.clone(),
-            )
-            .await,
-        );
+        let mut tasks = Vec::with_capacity(scraper.jobs.len());
+        for job in scraper.jobs.iter() {
+            trace!(
+                chain = %domain.name(),
+                job = job.event_label(),
+                builder = job.cursor_builder_name(),
+                "Spawning indexer job"
+            );
+            let task = match job {
+                JobType::MessageDispatch => {
+                    self.build_message_indexer(
+                        domain.clone(),
+                        self.metrics.clone(),
+                        self.contract_sync_metrics.clone(),
+                        db.clone(),
+                        index_settings.clone(),
+                    )
+                    .await
+                }
+                JobType::MessageDelivery => {
+                    self.build_delivery_indexer(
+                        domain.clone(),
+                        self.metrics.clone(),
+                        self.contract_sync_metrics.clone(),
+                        db.clone(),
+                        index_settings.clone(),
+                    )
+                    .await
+                }
+                JobType::GasPayment => {
+                    self.build_interchain_gas_payment_indexer(
+                        domain.clone(),
+                        self.metrics.clone(),
+                        self.contract_sync_metrics.clone(),
+                        db.clone(),
+                        index_settings.clone(),
+                    )
+                    .await
+                }
+            };
+            tasks.push(task);
+        }
         run_all(tasks)
     }
+
+    /// Drains the notifier channel and forwards each event to the
+    /// configured `Notifier`, if one is set. A delivery failure is logged
+    /// and does not stop the drain loop.
+    async fn notify(&self) -> Option<Instrumented<JoinHandle<eyre::Result<()>>>> {
+        let notifier = self.notifier.clone()?;
+        let mut rx = self.notifier_rx.lock().await.take()?;
+        Some(
+            tokio::spawn(async move {
+                while let Some(event) = rx.recv().await {
+                    if let Err(error) = notifier.notify(&event).await {
+                        tracing::warn!(?error, ?event, "Failed to deliver notification");
+                    }
+                }
+                Ok(())
+            })
+            .instrument(info_span!("ScraperNotifier")),
+        )
+    }
+
+    /// Drives every configured `JobType` for this chain over its fixed
+    /// `BackfillRange`, one spawned task per job (mirroring `scrape`). Each
+    /// task fetches and stores its job's events directly from the chain in
+    /// `BACKFILL_CHUNK_SIZE`-block chunks, via the same indexer `scrape`
+    /// uses, rather than reading back rows already persisted. Used in
+    /// place of the continuous cursors for reproducible re-indexing of a
+    /// known window. Under `fail_fast`, the first chunk error across any
+    /// job signals every other job's task (via a shared abort flag) to
+    /// stop at its next chunk boundary instead of continuing; otherwise
+    /// each job logs its own chunk errors and keeps going independently.
+    async fn backfill(&self, domain_id: u32) -> Instrumented<JoinHandle<eyre::Result<()>>> {
+        let scraper = self.scrapers.get(&domain_id).unwrap();
+        let range = scraper
+            .backfill
+            .expect("backfill task spawned without a configured range");
+        let db = scraper.db.clone();
+        let domain = scraper.domain.clone();
+        let jobs = scraper.jobs.clone();
+        let fail_fast = self.fail_fast;
+        let abort = Arc::new(AtomicBool::new(false));
+
+        trace!(chain = %domain.name(), range = ?range.range(), "Starting backfill");
+
+        let mut tasks = Vec::with_capacity(jobs.len());
+        for job in jobs.iter() {
+            let task = match job {
+                JobType::MessageDispatch => {
+                    self.backfill_message_indexer(
+                        domain.clone(),
+                        self.metrics.clone(),
+                        self.contract_sync_metrics.clone(),
+                        db.clone(),
+                        range,
+                        fail_fast,
+                        abort.clone(),
+                    )
+                    .await
+                }
+                JobType::MessageDelivery => {
+                    self.backfill_delivery_indexer(
+                        domain.clone(),
+                        self.metrics.clone(),
+                        self.contract_sync_metrics.clone(),
+                        db.clone(),
+                        range,
+                        fail_fast,
+                        abort.clone(),
+                    )
+                    .await
+                }
+                JobType::GasPayment => {
+                    self.backfill_interchain_gas_payment_indexer(
+                        domain.clone(),
+                        self.metrics.clone(),
+                        self.contract_sync_metrics.clone(),
+                        db.clone(),
+                        range,
+                        fail_fast,
+                        abort.clone(),
+                    )
+                    .await
+                }
+            };
+            tasks.push(task);
+        }
+        run_all(tasks)
+    }
+
+    /// Periodically checks whether a chain's indexed tip has fallen more
+    /// than `lag_threshold_blocks` behind the provider's finalized block,
+    /// and emits a `chain_lagging` notification when it has.
+    async fn watch_lag(&self, domain_id: u32) -> Instrumented<JoinHandle<eyre::Result<()>>> {
+        let scraper = self.scrapers.get(&domain_id).unwrap();
+        let db = scraper.db.clone();
+        let domain = scraper.domain.clone();
+        let tx = self.notifier_tx.clone();
+        let lag_threshold_blocks = self.lag_threshold_blocks;
+
+        tokio::spawn(async move {
+            let Some(tx) = tx else {
+                return Ok(());
+            };
+            let mut interval = tokio::time::interval(Duration::from_secs(60));
+            loop {
+                interval.tick().await;
+                let indexed_tip = db.last_indexed_block().await?;
+                let finalized_tip = db.provider_finalized_block().await?;
+                if finalized_tip.saturating_sub(indexed_tip) > lag_threshold_blocks {
+                    let event = NotificationEvent {
+                        domain_id: domain.id(),
+                        event_label: "chain_lagging",
+                        block_number: indexed_tip,
+                        identifier: String::new(),
+                    };
+                    // Never block the lag check on a slow/stuck notifier:
+                    // drop the notification rather than back-pressure it.
+                    if let Err(error) = tx.try_send(event) {
+                        tracing::warn!(
+                            chain = %domain.name(),
+                            ?error,
+                            "Dropping chain_lagging notification; notifier channel full or closed"
+                        );
+                    }
+                }
+            }
+        })
+        .instrument(info_span!("ChainLagWatcher", chain = %domain.name()))
+    }
 }
 
 impl AsRef<HyperlaneAgentCore> for Scraper {
@@ -211,9 +492,13 @@ impl AsRef<HyperlaneAgentCore> for Scraper {
     }
 }
 
-/// Create a function to spawn task that syncs contract events
+/// Create a pair of functions for a `JobType`: one that spawns the
+/// continuous, cursor-driven sync task `scrape` uses, and one that spawns
+/// a bounded one-shot backfill task over an explicit `BackfillRange`,
+/// chunk by chunk, driving the same underlying indexer directly instead
+/// of reading back rows `scrape` already persisted.
 macro_rules! spawn_sync_task {
-    ($name:ident, $cursor: ident, $label:literal) => {
+    ($name:ident, $backfill_name:ident, $cursor: ident, $label:literal) => {
         async fn $name(
             &self,
             domain: HyperlaneDomain,
@@ -243,21 +528,100 @@ macro_rules! spawn_sync_task {
                 })
                 .instrument(info_span!("ChainContractSync", chain=%domain.name(), event=$label))
         }
+
+        async fn $backfill_name(
+            &self,
+            domain: HyperlaneDomain,
+            metrics: Arc<CoreMetrics>,
+            contract_sync_metrics: Arc<ContractSyncMetrics>,
+            db: HyperlaneSqlDb,
+            range: BackfillRange,
+            fail_fast: bool,
+            abort: Arc<AtomicBool>,
+        ) -> Instrumented<JoinHandle<eyre::Result<()>>> {
+            let sync = self
+                .as_ref()
+                .settings
+                .$name(
+                    &domain,
+                    &metrics.clone(),
+                    &contract_sync_metrics.clone(),
+                    Arc::new(db.clone()),
+                )
+                .await
+                .unwrap();
+            let chunks = range.chunks();
+            tokio::spawn(async move {
+                for chunk in chunks {
+                    if abort.load(Ordering::Relaxed) {
+                        trace!(
+                            chain = %domain.name(),
+                            job = $label,
+                            "Aborting backfill; another job hit a fail_fast error"
+                        );
+                        break;
+                    }
+                    trace!(
+                        chain = %domain.name(),
+                        job = $label,
+                        from = chunk.start(),
+                        to = chunk.end(),
+                        "Backfilling chunk"
+                    );
+                    match sync.fetch_logs_in_range($label, chunk.clone()).await {
+                        Ok(stored) => {
+                            contract_sync_metrics
+                                .indexed_height
+                                .with_label_values(&[$label, domain.name()])
+                                .set(*chunk.end() as i64);
+                            contract_sync_metrics
+                                .stored_events
+                                .with_label_values(&[$label, domain.name()])
+                                .inc_by(stored as u64);
+                        }
+                        Err(error) if fail_fast => {
+                            abort.store(true, Ordering::Relaxed);
+                            return Err(error).wrap_err_with(|| {
+                                format!(
+                                    "Backfill of {:?} failed for job `{}` on chain {}",
+                                    chunk,
+                                    $label,
+                                    domain.name()
+                                )
+                            });
+                        }
+                        Err(error) => {
+                            tracing::warn!(
+                                ?error,
+                                chain = %domain.name(),
+                                job = $label,
+                                "Backfill chunk failed; continuing"
+                            );
+                        }
+                    }
+                }
+                Ok(())
+            })
+            .instrument(info_span!("ChainBackfill", chain = %domain.name(), event = $label))
+        }
     }
 }
 impl Scraper {
     spawn_sync_task!(
         build_message_indexer,
+        backfill_message_indexer,
         forward_message_sync_cursor,
         "message_dispatch"
     );
     spawn_sync_task!(
         build_delivery_indexer,
+        backfill_delivery_indexer,
         rate_limited_cursor,
         "message_delivery"
     );
     spawn_sync_task!(
         build_interchain_gas_payment_indexer,
+        backfill_interchain_gas_payment_indexer,
         rate_limited_cursor,
         "gas_payment"
     );
@@ -0,0 +1,92 @@
+//! `scraperctl`: a small control binary for operating a running scraper
+//! deployment's database without hand-writing SQL. Shares `ScraperDb`
+//! with the `scraper` agent and reads the same `db`
+//! connection config, so it always talks to the schema the agent writes.
+
+use clap::{Parser, Subcommand};
+
+use scraper::db::ScraperDb;
+
+#[derive(Parser)]
+#[command(name = "scraperctl", about = "Operate a scraper deployment's database")]
+struct Cli {
+    /// Path to the scraper agent's config file, used to read the `db`
+    /// connection settings.
+    #[arg(long, default_value = "config/scraper-config.json")]
+    config: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Show every indexed domain and its current high-water block.
+    ListChains,
+    /// Rewind a specific indexer's stored position so the next run
+    /// re-scrapes from `to_block`.
+    ResetCursor {
+        /// The chain to reset.
+        #[arg(long)]
+        domain: String,
+        /// The event / job label, e.g. `message_dispatch`.
+        #[arg(long)]
+        event: String,
+        /// The block to rewind the cursor to.
+        #[arg(long)]
+        to_block: u32,
+    },
+    /// Report missing block ranges between indexed events for a chain.
+    Gaps {
+        /// The chain to inspect.
+        #[arg(long)]
+        domain: String,
+    },
+    /// Delete indexed rows for a chain older than a given block.
+    Prune {
+        /// The chain to prune.
+        #[arg(long)]
+        domain: String,
+        /// Delete rows indexed before this block.
+        #[arg(long)]
+        before_block: u32,
+    },
+}
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let cli = Cli::parse();
+    let db = ScraperDb::connect_from_config(&cli.config).await?;
+
+    match cli.command {
+        Command::ListChains => {
+            let chains = db.list_chains().await?;
+            for chain in chains {
+                println!("domain {}: high-water block {}", chain.domain_id, chain.tip_block);
+            }
+        }
+        Command::ResetCursor {
+            domain,
+            event,
+            to_block,
+        } => {
+            db.reset_cursor(&domain, &event, to_block).await?;
+            println!("Reset `{domain}`/`{event}` cursor to block {to_block}");
+        }
+        Command::Gaps { domain } => {
+            let gaps = db.find_gaps(&domain).await?;
+            if gaps.is_empty() {
+                println!("No gaps found for `{domain}`");
+            }
+            for gap in gaps {
+                println!("{}..={}", gap.start(), gap.end());
+            }
+        }
+        Command::Prune { domain, before_block } => {
+            let pruned = db.prune(&domain, before_block).await?;
+            println!("Pruned {pruned} rows for `{domain}` before block {before_block}");
+        }
+    }
+
+    Ok(())
+}
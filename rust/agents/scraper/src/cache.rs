@@ -0,0 +1,183 @@
+use std::hash::Hash;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+use lru::LruCache;
+use serde::Deserialize;
+
+/// Default capacity for each of `HyperlaneSqlDb`'s entity caches (tx hash ->
+/// tx id, block number -> block id, address -> id) when not configured.
+pub const DEFAULT_CACHE_CAPACITY: usize = 10_000;
+
+/// Per-cache capacities for the entity caches inside `HyperlaneSqlDb`.
+#[derive(Debug, Clone, Copy)]
+pub struct EntityCacheCapacities {
+    /// Capacity of the transaction hash -> tx id cache.
+    pub tx: usize,
+    /// Capacity of the block number -> block id cache.
+    pub block: usize,
+    /// Capacity of the address -> id cache.
+    pub address: usize,
+}
+
+impl Default for EntityCacheCapacities {
+    fn default() -> Self {
+        Self {
+            tx: DEFAULT_CACHE_CAPACITY,
+            block: DEFAULT_CACHE_CAPACITY,
+            address: DEFAULT_CACHE_CAPACITY,
+        }
+    }
+}
+
+/// Raw, deserialized form of [`EntityCacheCapacities`].
+#[derive(Debug, Deserialize)]
+pub struct RawEntityCacheCapacities {
+    /// Capacity of the transaction hash -> tx id cache.
+    pub tx: Option<usize>,
+    /// Capacity of the block number -> block id cache.
+    pub block: Option<usize>,
+    /// Capacity of the address -> id cache.
+    pub address: Option<usize>,
+}
+
+impl From<RawEntityCacheCapacities> for EntityCacheCapacities {
+    fn from(raw: RawEntityCacheCapacities) -> Self {
+        let defaults = EntityCacheCapacities::default();
+        Self {
+            tx: raw.tx.unwrap_or(defaults.tx),
+            block: raw.block.unwrap_or(defaults.block),
+            address: raw.address.unwrap_or(defaults.address),
+        }
+    }
+}
+
+/// What to do with a cache entry when the backing store is written.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CacheUpdatePolicy {
+    /// Replace the cached value with the newly written one.
+    Overwrite,
+    /// Drop the cached value; the next read repopulates it from the store.
+    /// Used for writes (e.g. a reorg-driven delete) where the new value
+    /// isn't known at the write site.
+    Remove,
+}
+
+/// A bounded, write-through cache in front of a single lookup the scraper
+/// repeats under high event throughput (transaction hash -> tx id, block
+/// number -> block id, address -> id, ...).
+///
+/// Every read, write and eviction takes this cache's own mutex, so a
+/// reorg-driven delete and a read from another indexer are serialized
+/// against each other. The mutex is not held across the backing-store
+/// query a caller issues between a miss and the following write.
+#[derive(Debug)]
+pub struct WriteThroughCache<K, V> {
+    inner: Mutex<LruCache<K, V>>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> WriteThroughCache<K, V> {
+    /// Builds a cache holding at most `capacity` entries.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            inner: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Consults the cache for `key`, without touching the backing store.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.inner.lock().unwrap().get(key).cloned()
+    }
+
+    /// Records that `key` now resolves to `value`, e.g. after a lookup
+    /// against the backing store.
+    pub fn populate(&self, key: K, value: V) {
+        self.inner.lock().unwrap().put(key, value);
+    }
+
+    /// Applies a write to the backing store's entry for `key` to the
+    /// cache, per `policy`.
+    pub fn on_write(&self, key: K, value: V, policy: CacheUpdatePolicy) {
+        let mut cache = self.inner.lock().unwrap();
+        match policy {
+            CacheUpdatePolicy::Overwrite => {
+                cache.put(key, value);
+            }
+            CacheUpdatePolicy::Remove => {
+                cache.pop(&key);
+            }
+        }
+    }
+
+    /// Evicts `key`, e.g. when a reorg invalidates a previously indexed
+    /// block or transaction.
+    pub fn invalidate(&self, key: &K) {
+        self.inner.lock().unwrap().pop(key);
+    }
+
+    /// Atomically overwrites `key` with `value` and returns the value it
+    /// previously held, all under a single lock acquisition. Used where a
+    /// caller needs to compare-then-act on the prior value (e.g. detecting
+    /// that it changed) without racing a concurrent writer's read-then-write
+    /// of the same key.
+    pub fn swap(&self, key: K, value: V) -> Option<V> {
+        let mut cache = self.inner.lock().unwrap();
+        let previous = cache.get(&key).cloned();
+        cache.put(key, value);
+        previous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache() -> WriteThroughCache<&'static str, i64> {
+        WriteThroughCache::new(NonZeroUsize::new(2).unwrap())
+    }
+
+    #[test]
+    fn on_write_overwrite_replaces_the_cached_value() {
+        let cache = cache();
+        cache.populate("a", 1);
+        cache.on_write("a", 2, CacheUpdatePolicy::Overwrite);
+        assert_eq!(cache.get(&"a"), Some(2));
+    }
+
+    #[test]
+    fn on_write_overwrite_populates_a_previously_absent_key() {
+        let cache = cache();
+        cache.on_write("a", 1, CacheUpdatePolicy::Overwrite);
+        assert_eq!(cache.get(&"a"), Some(1));
+    }
+
+    #[test]
+    fn on_write_remove_evicts_the_cached_value() {
+        let cache = cache();
+        cache.populate("a", 1);
+        cache.on_write("a", 0, CacheUpdatePolicy::Remove);
+        assert_eq!(cache.get(&"a"), None);
+    }
+
+    #[test]
+    fn on_write_remove_is_a_no_op_for_an_absent_key() {
+        let cache = cache();
+        cache.on_write("a", 0, CacheUpdatePolicy::Remove);
+        assert_eq!(cache.get(&"a"), None);
+    }
+
+    #[test]
+    fn swap_returns_none_and_populates_an_absent_key() {
+        let cache = cache();
+        assert_eq!(cache.swap("a", 1), None);
+        assert_eq!(cache.get(&"a"), Some(1));
+    }
+
+    #[test]
+    fn swap_returns_the_previous_value_and_overwrites_it() {
+        let cache = cache();
+        cache.populate("a", 1);
+        assert_eq!(cache.swap("a", 2), Some(1));
+        assert_eq!(cache.get(&"a"), Some(2));
+    }
+}
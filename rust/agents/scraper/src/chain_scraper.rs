@@ -0,0 +1,244 @@
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use hyperlane_base::chains::IndexSettings;
+use hyperlane_base::HyperlaneLogStore;
+use hyperlane_core::{
+    HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, Indexed, InterchainGasPayment, LogMeta,
+    H160, H256,
+};
+use tokio::sync::mpsc::Sender;
+use tracing::warn;
+
+use crate::cache::{CacheUpdatePolicy, EntityCacheCapacities, WriteThroughCache};
+use crate::db::ScraperDb;
+use crate::notifier::NotificationEvent;
+
+/// A single newly indexed row, used both to persist the row and to build
+/// the `NotificationEvent` pushed onto the notifier channel for it.
+#[derive(Debug, Clone)]
+pub struct IndexedItem {
+    /// The event label, e.g. `message_dispatch`.
+    pub event_label: &'static str,
+    /// The block the event occurred in.
+    pub block_number: u64,
+    /// The transaction hash the event was emitted in.
+    pub tx_hash: H256,
+    /// A stable identifier for the row (message id or tx hash).
+    pub identifier: String,
+}
+
+/// The scraper's view of a single chain's database state: resolves foreign
+/// keys (tx hash, block number, address -> row id) through a write-through
+/// cache before falling back to Postgres, and persists indexed rows.
+#[derive(Debug, Clone)]
+pub struct HyperlaneSqlDb {
+    db: ScraperDb,
+    mailbox: H160,
+    domain: HyperlaneDomain,
+    provider: Arc<dyn HyperlaneProvider>,
+    index_settings: IndexSettings,
+    notifier_tx: Option<Sender<NotificationEvent>>,
+    tx_cache: Arc<WriteThroughCache<H256, i64>>,
+    block_cache: Arc<WriteThroughCache<u64, i64>>,
+    address_cache: Arc<WriteThroughCache<H160, i64>>,
+    /// The last block number a given transaction hash was observed in,
+    /// used to detect a reorg (the same tx resolving to a different
+    /// height) so the stale `block_cache` entry for its old height can be
+    /// evicted instead of silently reused. Keyed by tx hash rather than
+    /// block number, since multiple distinct transactions (and thus event
+    /// types) legitimately share one block height.
+    tx_block_cache: Arc<WriteThroughCache<H256, u64>>,
+}
+
+impl HyperlaneSqlDb {
+    /// Builds a `HyperlaneSqlDb` for a single chain.
+    pub async fn new(
+        db: ScraperDb,
+        mailbox: H160,
+        domain: HyperlaneDomain,
+        provider: Arc<dyn HyperlaneProvider>,
+        index_settings: &IndexSettings,
+        notifier_tx: Option<Sender<NotificationEvent>>,
+        cache_capacities: EntityCacheCapacities,
+    ) -> eyre::Result<Self> {
+        let cap = |n: usize| NonZeroUsize::new(n.max(1)).unwrap();
+        Ok(Self {
+            db,
+            mailbox,
+            domain,
+            provider,
+            index_settings: index_settings.clone(),
+            notifier_tx,
+            tx_cache: Arc::new(WriteThroughCache::new(cap(cache_capacities.tx))),
+            block_cache: Arc::new(WriteThroughCache::new(cap(cache_capacities.block))),
+            address_cache: Arc::new(WriteThroughCache::new(cap(cache_capacities.address))),
+            tx_block_cache: Arc::new(WriteThroughCache::new(cap(cache_capacities.tx))),
+        })
+    }
+
+    /// Resolves a transaction hash to its row id, consulting the
+    /// write-through cache before issuing a query.
+    pub async fn resolve_tx_id(&self, tx_hash: H256) -> eyre::Result<i64> {
+        if let Some(id) = self.tx_cache.get(&tx_hash) {
+            return Ok(id);
+        }
+        let id = self.db.resolve_or_insert_tx(self.domain.id(), tx_hash).await?;
+        self.tx_cache
+            .on_write(tx_hash, id, CacheUpdatePolicy::Overwrite);
+        Ok(id)
+    }
+
+    /// Resolves a block number to its row id, consulting the write-through
+    /// cache before issuing a query.
+    pub async fn resolve_block_id(&self, block_number: u64) -> eyre::Result<i64> {
+        if let Some(id) = self.block_cache.get(&block_number) {
+            return Ok(id);
+        }
+        let id = self
+            .db
+            .resolve_or_insert_block(self.domain.id(), block_number)
+            .await?;
+        self.block_cache
+            .on_write(block_number, id, CacheUpdatePolicy::Overwrite);
+        Ok(id)
+    }
+
+    /// Resolves a recipient/sender address to its row id, consulting the
+    /// write-through cache before issuing a query.
+    pub async fn resolve_address_id(&self, address: H160) -> eyre::Result<i64> {
+        if let Some(id) = self.address_cache.get(&address) {
+            return Ok(id);
+        }
+        let id = self.db.resolve_or_insert_address(address).await?;
+        self.address_cache
+            .on_write(address, id, CacheUpdatePolicy::Overwrite);
+        Ok(id)
+    }
+
+    /// A reorg (or any other correction) invalidates a previously resolved
+    /// block, so the next lookup re-queries Postgres instead of serving a
+    /// stale cached id.
+    pub fn invalidate_block(&self, block_number: u64) {
+        self.block_cache
+            .on_write(block_number, 0, CacheUpdatePolicy::Remove);
+    }
+
+    /// Persists a single indexed row and, if a notifier is configured,
+    /// pushes the corresponding event onto the bounded notifier channel.
+    /// This is the common tail end of every `store_*` path (message
+    /// dispatch, delivery, gas payment).
+    pub async fn store_indexed_item(&self, item: IndexedItem) -> eyre::Result<()> {
+        // `swap` reads and overwrites the previous height under a single
+        // lock acquisition, so two job tasks racing to store events from
+        // the same transaction can't each act on a `previous_block` the
+        // other has already superseded.
+        if let Some(previous_block) = self.tx_block_cache.swap(item.tx_hash, item.block_number) {
+            if previous_block != item.block_number {
+                // The chain reorged: this transaction now resolves to a
+                // different height than the one we last cached it under,
+                // so the old height's cached block id may be stale.
+                self.invalidate_block(previous_block);
+            }
+        }
+
+        let tx_id = self.resolve_tx_id(item.tx_hash).await?;
+        let block_id = self.resolve_block_id(item.block_number).await?;
+        self.db
+            .insert_indexed_row(self.domain.id(), item.event_label, tx_id, block_id, &item.identifier)
+            .await?;
+
+        if let Some(tx) = &self.notifier_tx {
+            let event = NotificationEvent {
+                domain_id: self.domain.id(),
+                event_label: item.event_label,
+                block_number: item.block_number,
+                identifier: item.identifier,
+            };
+            // Sync tasks must never block on a slow/stuck notifier: drop
+            // the notification rather than back-pressure indexing.
+            if let Err(error) = tx.try_send(event) {
+                warn!(
+                    chain = self.domain.id(),
+                    event = item.event_label,
+                    ?error,
+                    "Dropping notification; notifier channel full or closed"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// The highest block number this chain has indexed so far.
+    pub async fn last_indexed_block(&self) -> eyre::Result<u64> {
+        self.db.high_water_mark(self.domain.id()).await
+    }
+
+    /// The provider's current finalized block, used to compute indexing
+    /// lag for the `chain_lagging` notification.
+    pub async fn provider_finalized_block(&self) -> eyre::Result<u64> {
+        self.provider
+            .get_finalized_block_number()
+            .await
+            .map(u64::from)
+            .map_err(Into::into)
+    }
+}
+
+/// Message dispatches are stored (and notified) through the common
+/// `store_indexed_item` path, keyed by the message id.
+#[async_trait]
+impl HyperlaneLogStore<HyperlaneMessage> for HyperlaneSqlDb {
+    async fn store_logs(&self, logs: &[(Indexed<HyperlaneMessage>, LogMeta)]) -> eyre::Result<u32> {
+        for (message, meta) in logs {
+            self.store_indexed_item(IndexedItem {
+                event_label: "message_dispatch",
+                block_number: meta.block_number,
+                tx_hash: meta.transaction_hash,
+                identifier: message.inner().id().to_string(),
+            })
+            .await?;
+        }
+        Ok(logs.len() as u32)
+    }
+}
+
+/// Message deliveries are indexed by message id (`H256`), stored through
+/// the same common path as dispatches.
+#[async_trait]
+impl HyperlaneLogStore<H256> for HyperlaneSqlDb {
+    async fn store_logs(&self, logs: &[(Indexed<H256>, LogMeta)]) -> eyre::Result<u32> {
+        for (message_id, meta) in logs {
+            self.store_indexed_item(IndexedItem {
+                event_label: "message_delivery",
+                block_number: meta.block_number,
+                tx_hash: meta.transaction_hash,
+                identifier: message_id.inner().to_string(),
+            })
+            .await?;
+        }
+        Ok(logs.len() as u32)
+    }
+}
+
+/// Interchain gas payments are stored through the same common path,
+/// identified by the id of the message the payment is for.
+#[async_trait]
+impl HyperlaneLogStore<InterchainGasPayment> for HyperlaneSqlDb {
+    async fn store_logs(
+        &self,
+        logs: &[(Indexed<InterchainGasPayment>, LogMeta)],
+    ) -> eyre::Result<u32> {
+        for (payment, meta) in logs {
+            self.store_indexed_item(IndexedItem {
+                event_label: "gas_payment",
+                block_number: meta.block_number,
+                tx_hash: meta.transaction_hash,
+                identifier: payment.inner().message_id.to_string(),
+            })
+            .await?;
+        }
+        Ok(logs.len() as u32)
+    }
+}
@@ -0,0 +1,240 @@
+use std::ops::RangeInclusive;
+
+use hyperlane_core::{H160, H256};
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+
+use crate::db_config::DbConnection;
+
+/// Thin wrapper around the scraper's Postgres connection pool. Shared by
+/// the `scraper` agent (via `HyperlaneSqlDb`) and the `scraperctl` binary,
+/// so both always operate on the same schema.
+#[derive(Debug, Clone)]
+pub struct ScraperDb {
+    pool: PgPool,
+}
+
+/// A chain's current indexing state, as reported by `scraperctl list-chains`.
+#[derive(Debug, Clone)]
+pub struct ChainState {
+    /// The numeric Hyperlane domain id. `ScraperDb` has no access to a
+    /// chain's human-readable name, only the id every other row is keyed
+    /// by, so that's what's reported here.
+    pub domain_id: u32,
+    /// The highest block number with a stored indexed event for this
+    /// domain.
+    pub tip_block: u64,
+}
+
+impl ScraperDb {
+    /// Connects to Postgres using a structured [`DbConnection`], applying
+    /// the configured pool size and statement timeout unconditionally, and
+    /// wiring verified/mutual TLS when a `tls` block is present.
+    pub async fn connect(db: &DbConnection) -> eyre::Result<Self> {
+        let mut opts: PgPoolOptions = PgPoolOptions::new().max_connections(db.pool_size);
+
+        opts = opts.after_connect({
+            let statement_timeout = db.statement_timeout;
+            move |conn, _meta| {
+                Box::pin(async move {
+                    sqlx::query(&format!(
+                        "SET statement_timeout = {}",
+                        statement_timeout.as_millis()
+                    ))
+                    .execute(conn)
+                    .await?;
+                    Ok(())
+                })
+            }
+        });
+
+        let pool = if let Some(tls) = &db.tls {
+            let mut connect_opts: sqlx::postgres::PgConnectOptions = db.url.parse()?;
+            connect_opts = connect_opts
+                .ssl_mode(sqlx::postgres::PgSslMode::VerifyFull)
+                .ssl_root_cert(&tls.ca_cert);
+            if let (Some(cert), Some(key)) = (&tls.client_cert, &tls.client_key) {
+                connect_opts = connect_opts.ssl_client_cert(cert).ssl_client_key(key);
+            }
+            opts.connect_with(connect_opts).await?
+        } else {
+            opts.connect(&db.url).await?
+        };
+
+        Ok(Self { pool })
+    }
+
+    /// Connects using the `db` block of a scraper config file on disk, for
+    /// the `scraperctl` binary.
+    pub async fn connect_from_config(config_path: &str) -> eyre::Result<Self> {
+        let contents = std::fs::read_to_string(config_path)?;
+        let value: serde_json::Value = serde_json::from_str(&contents)?;
+        let raw_db = value
+            .get("db")
+            .ok_or_else(|| eyre::eyre!("Missing `db` block in {config_path}"))?
+            .clone();
+        let raw_db: crate::db_config::RawDbConnection = serde_json::from_value(raw_db)?;
+        let db = DbConnection::try_from(raw_db)?;
+        Self::connect(&db).await
+    }
+
+    /// Resolves a transaction hash to its row id, inserting a new row if
+    /// this is the first time the scraper has seen it.
+    pub async fn resolve_or_insert_tx(&self, domain_id: u32, tx_hash: H256) -> eyre::Result<i64> {
+        let id: (i64,) = sqlx::query_as(
+            "INSERT INTO transaction (domain_id, hash) VALUES ($1, $2) \
+             ON CONFLICT (domain_id, hash) DO UPDATE SET hash = EXCLUDED.hash \
+             RETURNING id",
+        )
+        .bind(domain_id as i64)
+        .bind(tx_hash.as_bytes())
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id.0)
+    }
+
+    /// Resolves a block number to its row id, inserting a new row if this
+    /// is the first time the scraper has seen it.
+    pub async fn resolve_or_insert_block(&self, domain_id: u32, block_number: u64) -> eyre::Result<i64> {
+        let id: (i64,) = sqlx::query_as(
+            "INSERT INTO block (domain_id, height) VALUES ($1, $2) \
+             ON CONFLICT (domain_id, height) DO UPDATE SET height = EXCLUDED.height \
+             RETURNING id",
+        )
+        .bind(domain_id as i64)
+        .bind(block_number as i64)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id.0)
+    }
+
+    /// Resolves an address to its row id, inserting a new row if this is
+    /// the first time the scraper has seen it.
+    pub async fn resolve_or_insert_address(&self, address: H160) -> eyre::Result<i64> {
+        let id: (i64,) = sqlx::query_as(
+            "INSERT INTO address (address) VALUES ($1) \
+             ON CONFLICT (address) DO UPDATE SET address = EXCLUDED.address \
+             RETURNING id",
+        )
+        .bind(address.as_bytes())
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id.0)
+    }
+
+    /// Persists a single indexed row keyed by its resolved tx/block ids.
+    pub async fn insert_indexed_row(
+        &self,
+        domain_id: u32,
+        event_label: &str,
+        tx_id: i64,
+        block_id: i64,
+        identifier: &str,
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            "INSERT INTO indexed_event (domain_id, event_label, tx_id, block_id, identifier) \
+             VALUES ($1, $2, $3, $4, $5) \
+             ON CONFLICT (domain_id, event_label, identifier) DO NOTHING",
+        )
+        .bind(domain_id as i64)
+        .bind(event_label)
+        .bind(tx_id)
+        .bind(block_id)
+        .bind(identifier)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// The highest block number with a stored indexed event for a domain,
+    /// across every event label. Derived from `indexed_event` (not the raw
+    /// `block` table, whose rows stay behind when `reset_cursor` or `prune`
+    /// deletes the `indexed_event` rows that reference them) so that
+    /// `reset_cursor` rewinding `indexed_event` is directly reflected here.
+    pub async fn high_water_mark(&self, domain_id: u32) -> eyre::Result<u64> {
+        let row: (Option<i64>,) = sqlx::query_as(
+            "SELECT MAX(b.height) FROM indexed_event e \
+             JOIN block b ON b.id = e.block_id \
+             WHERE e.domain_id = $1",
+        )
+        .bind(domain_id as i64)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(row.0.unwrap_or(0) as u64)
+    }
+
+    /// Every domain with at least one stored indexed event, with its
+    /// current high-water block.
+    pub async fn list_chains(&self) -> eyre::Result<Vec<ChainState>> {
+        let rows: Vec<(i64, Option<i64>)> = sqlx::query_as(
+            "SELECT e.domain_id, MAX(b.height) FROM indexed_event e \
+             JOIN block b ON b.id = e.block_id \
+             GROUP BY e.domain_id ORDER BY e.domain_id",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(domain_id, tip)| ChainState {
+                domain_id: domain_id as u32,
+                tip_block: tip.unwrap_or(0) as u64,
+            })
+            .collect())
+    }
+
+    /// Rewinds a domain/event's stored position so the next agent run
+    /// re-scrapes from `to_block`. Deletes the `indexed_event` rows
+    /// directly, which `high_water_mark`/`list_chains` derive their tip
+    /// from, so the rewind is immediately visible to the next run.
+    pub async fn reset_cursor(&self, domain: &str, event: &str, to_block: u32) -> eyre::Result<()> {
+        sqlx::query(
+            "DELETE FROM indexed_event e USING block b \
+             WHERE e.block_id = b.id AND b.domain_id::text = $1 \
+               AND e.event_label = $2 AND b.height >= $3",
+        )
+        .bind(domain)
+        .bind(event)
+        .bind(to_block as i64)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Finds gaps between the block heights that hold indexed events for a
+    /// domain.
+    pub async fn find_gaps(&self, domain: &str) -> eyre::Result<Vec<RangeInclusive<u64>>> {
+        let rows: Vec<(i64,)> = sqlx::query_as(
+            "SELECT DISTINCT b.height FROM indexed_event e \
+             JOIN block b ON b.id = e.block_id \
+             WHERE e.domain_id::text = $1 ORDER BY b.height",
+        )
+        .bind(domain)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut gaps = Vec::new();
+        let mut prev: Option<i64> = None;
+        for (height,) in rows {
+            if let Some(p) = prev {
+                if height > p + 1 {
+                    gaps.push((p as u64 + 1)..=(height as u64 - 1));
+                }
+            }
+            prev = Some(height);
+        }
+        Ok(gaps)
+    }
+
+    /// Deletes indexed rows for a domain older than `before_block`.
+    pub async fn prune(&self, domain: &str, before_block: u32) -> eyre::Result<u64> {
+        let result = sqlx::query(
+            "DELETE FROM indexed_event e USING block b \
+             WHERE e.block_id = b.id AND b.domain_id::text = $1 AND b.height < $2",
+        )
+        .bind(domain)
+        .bind(before_block as i64)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Connection pool size used when `pool_size` is omitted from the config.
+pub const DEFAULT_DB_POOL_SIZE: u32 = 10;
+/// Statement timeout used when `statement_timeout_secs` is omitted from the
+/// config.
+pub const DEFAULT_STATEMENT_TIMEOUT_SECS: u64 = 30;
+
+/// Structured database connection settings, replacing a bare connection
+/// string so that TLS, pool sizing and statement timeouts can be configured
+/// the way operators secure production Postgres instances.
+#[derive(Debug, Clone)]
+pub struct DbConnection {
+    /// The base connection string (host, port, database name, credentials).
+    pub url: String,
+    /// TLS material used to connect to databases that mandate verified or
+    /// mutual TLS. When absent, the scraper falls back to a plaintext
+    /// connection for backward compatibility.
+    pub tls: Option<DbTlsConfig>,
+    /// Maximum number of connections to keep open in the pool.
+    pub pool_size: u32,
+    /// Statement timeout applied to every connection in the pool.
+    pub statement_timeout: Duration,
+}
+
+/// TLS material used to establish a verified / mutual TLS connection to the
+/// scraper's Postgres database.
+#[derive(Debug, Clone)]
+pub struct DbTlsConfig {
+    /// Path to the CA certificate used to verify the server.
+    pub ca_cert: PathBuf,
+    /// Path to the client certificate presented for mutual TLS, if required.
+    pub client_cert: Option<PathBuf>,
+    /// Path to the client private key matching `client_cert`.
+    pub client_key: Option<PathBuf>,
+}
+
+/// Raw, deserialized form of [`DbConnection`] before validation.
+#[derive(Debug, Deserialize)]
+pub struct RawDbConnection {
+    /// The base connection string.
+    pub url: Option<String>,
+    /// Optional TLS block.
+    pub tls: Option<RawDbTlsConfig>,
+    /// Maximum number of connections to keep open in the pool.
+    pub pool_size: Option<u32>,
+    /// Statement timeout, in seconds.
+    pub statement_timeout_secs: Option<u64>,
+}
+
+/// Raw, deserialized form of [`DbTlsConfig`].
+#[derive(Debug, Deserialize)]
+pub struct RawDbTlsConfig {
+    /// Path to the CA certificate used to verify the server.
+    pub ca_cert: Option<String>,
+    /// Path to the client certificate presented for mutual TLS, if required.
+    pub client_cert: Option<String>,
+    /// Path to the client private key matching `client_cert`.
+    pub client_key: Option<String>,
+}
+
+impl TryFrom<RawDbConnection> for DbConnection {
+    type Error = eyre::Report;
+
+    fn try_from(raw: RawDbConnection) -> eyre::Result<Self> {
+        let url = raw
+            .url
+            .ok_or_else(|| eyre::eyre!("Missing `url` connection string"))?;
+
+        let tls = raw
+            .tls
+            .map(|tls| -> eyre::Result<DbTlsConfig> {
+                if tls.client_cert.is_some() != tls.client_key.is_some() {
+                    return Err(eyre::eyre!(
+                        "`client_cert` and `client_key` must both be set or both omitted"
+                    ));
+                }
+                let ca_cert = tls
+                    .ca_cert
+                    .ok_or_else(|| eyre::eyre!("TLS block requires `ca_cert`"))?;
+                Ok(DbTlsConfig {
+                    ca_cert: PathBuf::from(ca_cert),
+                    client_cert: tls.client_cert.map(PathBuf::from),
+                    client_key: tls.client_key.map(PathBuf::from),
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            url,
+            tls,
+            pool_size: raw.pool_size.unwrap_or(DEFAULT_DB_POOL_SIZE),
+            statement_timeout: Duration::from_secs(
+                raw.statement_timeout_secs
+                    .unwrap_or(DEFAULT_STATEMENT_TIMEOUT_SECS),
+            ),
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+use eyre::eyre;
+
+/// The category of on-chain event a single indexing task is responsible for
+/// syncing. Data-driven replacement for the three hardcoded
+/// `spawn_sync_task!` expansions, with room to grow as new event kinds are
+/// added.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum JobType {
+    /// Indexes `Dispatch` events emitted by the mailbox.
+    MessageDispatch,
+    /// Indexes message delivery (`Process`) events emitted by the mailbox.
+    MessageDelivery,
+    /// Indexes interchain gas payment events.
+    GasPayment,
+}
+
+impl JobType {
+    /// Every known job type, in the order the scraper has historically
+    /// spawned them. Used as the default when a chain has no explicit
+    /// `indexers` override.
+    pub fn all() -> Vec<JobType> {
+        vec![
+            JobType::MessageDispatch,
+            JobType::MessageDelivery,
+            JobType::GasPayment,
+        ]
+    }
+}
+
+impl FromStr for JobType {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "messagedispatch" | "message_dispatch" | "dispatch" => Ok(JobType::MessageDispatch),
+            "messagedelivery" | "message_delivery" | "delivery" => Ok(JobType::MessageDelivery),
+            "gaspayment" | "gas_payment" | "gas" => Ok(JobType::GasPayment),
+            other => Err(eyre!("Unknown indexer job type `{other}`")),
+        }
+    }
+}
+
+/// A single indexing job. Owns the name of the `Scraper` method that builds
+/// its cursor and the event label it syncs under. Adding a job type takes
+/// a new `spawn_sync_task!` invocation plus a match arm in both
+/// `Scraper::scrape` and `Scraper::backfill`; the macro itself is unchanged.
+pub trait IndexerJob {
+    /// The name of the `Scraper::build_*_indexer` method backing this job,
+    /// surfaced for diagnostics and logging.
+    fn cursor_builder_name(&self) -> &'static str;
+    /// The event label this job syncs under (used for tracing spans and
+    /// metrics, matching the `$label` passed to `spawn_sync_task!`).
+    fn event_label(&self) -> &'static str;
+}
+
+impl IndexerJob for JobType {
+    fn cursor_builder_name(&self) -> &'static str {
+        match self {
+            JobType::MessageDispatch => "build_message_indexer",
+            JobType::MessageDelivery => "build_delivery_indexer",
+            JobType::GasPayment => "build_interchain_gas_payment_indexer",
+        }
+    }
+
+    fn event_label(&self) -> &'static str {
+        match self {
+            JobType::MessageDispatch => "message_dispatch",
+            JobType::MessageDelivery => "message_delivery",
+            JobType::GasPayment => "gas_payment",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_alias() {
+        for (alias, expected) in [
+            ("messagedispatch", JobType::MessageDispatch),
+            ("message_dispatch", JobType::MessageDispatch),
+            ("dispatch", JobType::MessageDispatch),
+            ("  Dispatch ", JobType::MessageDispatch),
+            ("messagedelivery", JobType::MessageDelivery),
+            ("message_delivery", JobType::MessageDelivery),
+            ("delivery", JobType::MessageDelivery),
+            ("gaspayment", JobType::GasPayment),
+            ("gas_payment", JobType::GasPayment),
+            ("gas", JobType::GasPayment),
+        ] {
+            assert_eq!(alias.parse::<JobType>().unwrap(), expected, "alias: {alias}");
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_job_type() {
+        assert!("not_a_job".parse::<JobType>().is_err());
+    }
+
+    #[test]
+    fn all_have_non_empty_cursor_builder_and_event_labels() {
+        for job in JobType::all() {
+            assert!(!job.cursor_builder_name().is_empty());
+            assert!(!job.event_label().is_empty());
+        }
+    }
+}
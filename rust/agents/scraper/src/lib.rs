@@ -0,0 +1,12 @@
+//! The scraper agent indexes on-chain events (message dispatches,
+//! deliveries, gas payments, ...) into a Postgres database for explorers
+//! and other downstream consumers.
+
+pub mod agent;
+pub mod backfill;
+pub mod cache;
+pub mod chain_scraper;
+pub mod db;
+mod db_config;
+mod job;
+mod notifier;
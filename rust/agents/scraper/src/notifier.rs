@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// A single outbound notification describing a newly indexed row or a chain
+/// health condition (e.g. falling behind the provider's finalized tip).
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationEvent {
+    /// The domain id of the chain the event concerns.
+    pub domain_id: u32,
+    /// The kind of event, e.g. `message_dispatch`, `message_delivery`,
+    /// `gas_payment` or `chain_lagging`.
+    pub event_label: &'static str,
+    /// The block number the event occurred at (or the current tip, for a
+    /// `chain_lagging` notification).
+    pub block_number: u64,
+    /// A stable identifier for the event: a message id or transaction hash
+    /// for indexed rows, or empty for chain-level notifications.
+    pub identifier: String,
+}
+
+/// A destination for outbound [`NotificationEvent`]s.
+#[async_trait]
+pub trait Notifier: std::fmt::Debug + Send + Sync {
+    /// Deliver a single notification. Errors are logged by the caller and do
+    /// not tear down the notifier task.
+    async fn notify(&self, event: &NotificationEvent) -> eyre::Result<()>;
+}
+
+/// Per-agent configuration for a [`RemoteNotifier`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct RemoteNotifierConfig {
+    /// The URL to POST notification payloads to.
+    pub url: String,
+    /// An optional bearer token sent as `Authorization: Bearer <token>`.
+    pub auth_token: Option<String>,
+}
+
+/// Notifies a remote HTTP endpoint of newly indexed events by POSTing a
+/// small JSON payload.
+#[derive(Debug)]
+pub struct RemoteNotifier {
+    client: reqwest::Client,
+    url: String,
+    auth_token: Option<String>,
+}
+
+impl RemoteNotifier {
+    /// Builds a `RemoteNotifier` from its config.
+    pub fn new(config: RemoteNotifierConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: config.url,
+            auth_token: config.auth_token,
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for RemoteNotifier {
+    async fn notify(&self, event: &NotificationEvent) -> eyre::Result<()> {
+        let mut req = self.client.post(&self.url).json(event);
+        if let Some(token) = &self.auth_token {
+            req = req.bearer_auth(token);
+        }
+        req.send().await?.error_for_status()?;
+        Ok(())
+    }
+}